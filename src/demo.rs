@@ -0,0 +1,49 @@
+use winit::event::WindowEvent;
+
+/// Per-frame contract implemented by each toy that `State` can host.
+///
+/// This is the seam between the windowing/surface plumbing in `State` and
+/// the actual content of a demo, so new toys can be added without touching
+/// `State` itself.
+pub trait Demo {
+    /// Features the demo can make use of if the adapter supports them, but
+    /// can still run without.
+    fn optional_features() -> wgpu::Features
+    where
+        Self: Sized;
+
+    /// Features the demo cannot run without.
+    fn required_features() -> wgpu::Features
+    where
+        Self: Sized;
+
+    /// Minimum limits the demo needs the device to support.
+    fn required_limits() -> wgpu::Limits
+    where
+        Self: Sized;
+
+    /// Build the demo's GPU resources once the device and surface
+    /// configuration are known.
+    fn init(
+        config: &wgpu::SurfaceConfiguration,
+        adapter: &wgpu::Adapter,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self
+    where
+        Self: Sized;
+
+    /// Called whenever the surface is resized, so the demo can recreate any
+    /// size-dependent resources.
+    fn resize(&mut self, config: &wgpu::SurfaceConfiguration, device: &wgpu::Device, queue: &wgpu::Queue);
+
+    /// Handle a window event. Returns `true` if the event was consumed and
+    /// should not be processed further.
+    fn input(&mut self, event: &WindowEvent) -> bool;
+
+    /// Advance the demo's state by one frame.
+    fn update(&mut self, queue: &wgpu::Queue);
+
+    /// Record and submit the demo's draw commands for the given view.
+    fn render(&mut self, view: &wgpu::TextureView, device: &wgpu::Device, queue: &wgpu::Queue);
+}
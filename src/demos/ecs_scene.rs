@@ -0,0 +1,161 @@
+use bevy_ecs::prelude::*;
+use winit::event::WindowEvent;
+
+use crate::demo::Demo;
+
+#[derive(Resource)]
+struct DeviceRes(wgpu::Device);
+
+#[derive(Resource)]
+struct QueueRes(wgpu::Queue);
+
+#[derive(Resource)]
+struct SurfaceConfigRes(wgpu::SurfaceConfiguration);
+
+/// The current frame's target view, valid only for the duration of the
+/// `render_schedule` run that inserts it. Do not copy the inner reference
+/// out of this resource (into a field, an async task, etc.) — it is a
+/// lifetime-erased borrow that `RemoveViewOnDrop` invalidates as soon as
+/// `EcsScene::render` returns, and the `TextureView` it points to is torn
+/// down well before the next frame.
+#[derive(Resource)]
+struct ViewRes(&'static wgpu::TextureView);
+
+#[derive(Event)]
+struct CursorMoved {
+    x: f64,
+    y: f64,
+}
+
+/// A toy expressed as `bevy_ecs` components and systems instead of as state
+/// hand-edited into a bespoke `Demo` impl. Window input is forwarded into
+/// the world as events; `update`/`render` just run the two schedules.
+pub struct EcsScene {
+    world: World,
+    update_schedule: Schedule,
+    render_schedule: Schedule,
+}
+
+fn render_pass_system(view: Res<ViewRes>, device: Res<DeviceRes>, queue: Res<QueueRes>) {
+    let mut encoder = device.0.create_command_encoder(
+        &wgpu::CommandEncoderDescriptor {
+            label: Some("ECS Render Encoder"),
+        }
+    );
+
+    {
+        encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some("ECS Render Pass"),
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: view.0,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: wgpu::StoreOp::Store,
+                        }
+                    })
+                ],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            }
+        );
+    }
+
+    queue.0.submit(std::iter::once(encoder.finish()));
+}
+
+fn log_cursor_system(mut cursor_events: EventReader<CursorMoved>) {
+    for event in cursor_events.read() {
+        log::trace!("cursor moved to ({}, {})", event.x, event.y);
+    }
+}
+
+impl Demo for EcsScene {
+    fn optional_features() -> wgpu::Features {
+        wgpu::Features::empty()
+    }
+
+    fn required_features() -> wgpu::Features {
+        wgpu::Features::empty()
+    }
+
+    fn required_limits() -> wgpu::Limits {
+        wgpu::Limits::default()
+    }
+
+    fn init(
+        config: &wgpu::SurfaceConfiguration,
+        _adapter: &wgpu::Adapter,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self {
+        let mut world = World::new();
+        world.insert_resource(DeviceRes(device.clone()));
+        world.insert_resource(QueueRes(queue.clone()));
+        world.insert_resource(SurfaceConfigRes(config.clone()));
+        world.init_resource::<Events<CursorMoved>>();
+
+        let mut update_schedule = Schedule::default();
+        update_schedule.add_systems(
+            (
+                log_cursor_system,
+                bevy_ecs::event::event_update_system::<CursorMoved>,
+            )
+                .chain(),
+        );
+
+        let mut render_schedule = Schedule::default();
+        render_schedule.add_systems(render_pass_system);
+
+        Self {
+            world,
+            update_schedule,
+            render_schedule,
+        }
+    }
+
+    fn resize(&mut self, config: &wgpu::SurfaceConfiguration, _device: &wgpu::Device, _queue: &wgpu::Queue) {
+        self.world.insert_resource(SurfaceConfigRes(config.clone()));
+    }
+
+    fn input(&mut self, event: &WindowEvent) -> bool {
+        if let WindowEvent::CursorMoved { position, .. } = event {
+            self.world.resource_mut::<Events<CursorMoved>>().send(CursorMoved {
+                x: position.x,
+                y: position.y,
+            });
+        }
+
+        // Observing the cursor doesn't mean claiming it: let `State` (and
+        // any other interested demo behavior, like the clear-color driver)
+        // see the event too.
+        false
+    }
+
+    fn update(&mut self, _queue: &wgpu::Queue) {
+        self.update_schedule.run(&mut self.world);
+    }
+
+    fn render(&mut self, view: &wgpu::TextureView, _device: &wgpu::Device, _queue: &wgpu::Queue) {
+        // SAFETY: the `'static` cast only needs to be valid for as long as
+        // `ViewRes` lives in `self.world`. `RemoveViewOnDrop` guarantees the
+        // resource is removed again when this function returns, including
+        // if a system in `render_schedule` panics, so the dangling
+        // reference can never be observed afterward.
+        let view: &'static wgpu::TextureView = unsafe { std::mem::transmute(view) };
+        self.world.insert_resource(ViewRes(view));
+
+        struct RemoveViewOnDrop<'w>(&'w mut World);
+        impl Drop for RemoveViewOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.remove_resource::<ViewRes>();
+            }
+        }
+        let guard = RemoveViewOnDrop(&mut self.world);
+
+        self.render_schedule.run(&mut *guard.0);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ecs_scene;
+pub mod triangle;
@@ -1,27 +1,37 @@
+use std::sync::Arc;
+
 use winit::{
+    application::ApplicationHandler,
     event::*,
-    event_loop::EventLoop,
+    event_loop::{ActiveEventLoop, EventLoop, EventLoopProxy},
     keyboard::{KeyCode, PhysicalKey},
-    window::{WindowBuilder, Window},
+    window::{Window, WindowId},
 };
 
 #[cfg(target_arch="wasm32")]
 use wasm_bindgen::prelude::*;
 
-struct State<'a> {
-    surface: wgpu::Surface<'a>,
+mod demo;
+mod demos;
+
+use demo::Demo;
+
+/// The toy that `run()` currently hosts. Swap this to switch demos.
+type ActiveDemo = demos::ecs_scene::EcsScene;
+
+struct State<D: Demo> {
+    surface: wgpu::Surface<'static>,
     device: wgpu::Device,
     queue: wgpu::Queue,
     config: wgpu::SurfaceConfiguration,
     size: winit::dpi::PhysicalSize<u32>,
-    // The window must be declared after the surface
-    // so it gets dropped after it, due to the surface
-    // containing unsafe references to the window's resources.
-    window: &'a Window,
+    clear_color: wgpu::Color,
+    demo: D,
+    window: Arc<Window>,
 }
 
-impl<'a> State<'a> {
-    async fn new(window: &'a Window) -> State<'a> {
+impl<D: Demo> State<D> {
+    async fn new(window: Arc<Window>) -> State<D> {
         let size = window.inner_size();
 
         let instance = wgpu::Instance::new(
@@ -32,7 +42,7 @@ impl<'a> State<'a> {
         );
 
         let surface = instance
-            .create_surface(window)
+            .create_surface(window.clone())
             .expect("could not create surface");
 
         let adapter = instance.request_adapter(
@@ -45,26 +55,61 @@ impl<'a> State<'a> {
         .await
         .expect("could not request adapter");
 
-        let (device, queue) = adapter
-            .request_device(
-                &wgpu::DeviceDescriptor {
-                    label: None,
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
-                    memory_hints: wgpu::MemoryHints::default(),
-                },
-                None,
-            )
-            .await
-            .expect("could not request device");
+        let adapter_features = adapter.features();
+        let required_features = D::required_features();
+        let optional_features = D::optional_features();
+
+        let available_optional = optional_features & adapter_features;
+        let missing_optional = optional_features - available_optional;
+        if !missing_optional.is_empty() {
+            log::warn!("optional features not available on this adapter: {missing_optional:?}");
+        }
+
+        let downlevel_capabilities = adapter.get_downlevel_capabilities();
+        let missing_downlevel_flags = wgpu::DownlevelFlags::all() - downlevel_capabilities.flags;
+        if !missing_downlevel_flags.is_empty() {
+            log::warn!("adapter is missing downlevel capabilities: {missing_downlevel_flags:?}; some demos may not render correctly");
+        }
+
+        // `Limits::default()` exceeds what the wasm32 WebGL2 backend can
+        // provide, so fall back to the downlevel defaults there.
+        let required_limits = if cfg!(target_arch = "wasm32") {
+            wgpu::Limits::downlevel_webgl2_defaults()
+        } else {
+            D::required_limits()
+        };
+
+        let device_descriptor = wgpu::DeviceDescriptor {
+            label: None,
+            required_features: required_features | available_optional,
+            required_limits,
+            memory_hints: wgpu::MemoryHints::default(),
+        };
+
+        let (device, queue) = match adapter.request_device(&device_descriptor, None).await {
+            Ok(pair) => pair,
+            Err(err) => {
+                log::error!("could not request device with {:?}: {err}; retrying with WebGL2 downlevel defaults", device_descriptor.required_limits);
+                adapter
+                    .request_device(
+                        &wgpu::DeviceDescriptor {
+                            required_limits: wgpu::Limits::downlevel_webgl2_defaults(),
+                            ..device_descriptor
+                        },
+                        None,
+                    )
+                    .await
+                    .expect("could not request device even with downlevel defaults")
+            }
+        };
 
         let surface_caps = surface.get_capabilities(&adapter);
-        
+
         let surface_fmt = surface_caps.formats.iter()
             .find(|fmt| fmt.is_srgb())
             .copied()
             .unwrap_or(surface_caps.formats[0]);
-        
+
         let config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             format: surface_fmt,
@@ -76,57 +121,81 @@ impl<'a> State<'a> {
             view_formats: vec![],
         };
 
+        let demo = D::init(&config, &adapter, &device, &queue);
+
+        let clear_color = wgpu::Color { r: 0.1, g: 0.2, b: 0.3, a: 1.0 };
+
         Self {
             surface,
             device,
             queue,
             config,
             size,
+            clear_color,
+            demo,
             window,
         }
     }
-    
+
     pub fn window(&self) -> &Window {
         &self.window
     }
-    
+
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
             self.size = new_size;
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             self.surface.configure(&self.device, &self.config);
+            self.demo.resize(&self.config, &self.device, &self.queue);
         }
     }
 
     fn input(&mut self, event: &WindowEvent) -> bool {
+        // Give the demo first refusal so it can still observe (or consume)
+        // cursor movement itself; only fall back to driving the clear
+        // color when the demo doesn't care about the event.
+        if self.demo.input(event) {
+            return true;
+        }
+
+        if let WindowEvent::CursorMoved { position, .. } = event {
+            self.clear_color = wgpu::Color {
+                r: position.x / self.size.width as f64,
+                g: position.y / self.size.height as f64,
+                b: 1.0,
+                a: 1.0,
+            };
+            return true;
+        }
+
         false
     }
 
-    fn update(&mut self) { }
+    fn update(&mut self) {
+        self.demo.update(&self.queue);
+    }
 
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         let output = self.surface.get_current_texture()?;
         let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
-        
+
         let mut encoder = self.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor {
-                label: Some("Render Encoder")
+                label: Some("Clear Encoder")
             }
         );
-        
-        {
-            let clear_color = wgpu::Color { r: 0.1, g: 0.2, b: 0.3, a: 1.0 };
 
+        {
             encoder.begin_render_pass(
                 &wgpu::RenderPassDescriptor {
-                    label: Some("Render Pass"),
+                    label: Some("Clear Pass"),
                     color_attachments: &[
                         Some(wgpu::RenderPassColorAttachment {
                             view: &view,
                             resolve_target: None,
                             ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(clear_color),
+                                load: wgpu::LoadOp::Clear(self.clear_color),
                                 store: wgpu::StoreOp::Store,
                             }
                         })
@@ -137,92 +206,140 @@ impl<'a> State<'a> {
                 }
             );
         }
-        
+
         self.queue.submit(std::iter::once(encoder.finish()));
+
+        self.demo.render(&view, &self.device, &self.queue);
+
         output.present();
 
         Ok(())
     }
 }
 
-#[cfg_attr(target_arch="wasm32", wasm_bindgen(start))]
-pub async fn run() {
-    let event_loop = EventLoop::new().unwrap();
-    let window = WindowBuilder::new().build(&event_loop).unwrap();
-
-    #[cfg(target_arch = "wasm32")]
-    {
-        // Winit prevents sizing with CSS, so we have to set
-        // the size manually when on web.
-        use winit::dpi::PhysicalSize;
-        let _ = window.request_inner_size(PhysicalSize::new(450, 400));
-
-        use winit::platform::web::WindowExtWebSys;
-        web_sys::window()
-            .and_then(|win| win.document())
-            .and_then(|doc| {
-                let dst = doc.get_element_by_id("wasm-example")?;
-                let canvas = web_sys::Element::from(window.canvas()?);
-                dst.append_child(&canvas).ok()?;
-                Some(())
-            })
-            .expect("Couldn't append canvas to document body.");
+/// Fired once the asynchronous `State::new` future resolves, since
+/// `ApplicationHandler::resumed` itself can't await on wasm32.
+enum UserEvent {
+    StateReady(State<ActiveDemo>),
+}
+
+#[derive(Default)]
+struct App {
+    proxy: Option<EventLoopProxy<UserEvent>>,
+    state: Option<State<ActiveDemo>>,
+    surface_configured: bool,
+}
+
+impl ApplicationHandler<UserEvent> for App {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let window_attributes = Window::default_attributes();
+        let window = Arc::new(
+            event_loop.create_window(window_attributes).unwrap()
+        );
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            // Winit prevents sizing with CSS, so we have to set
+            // the size manually when on web.
+            use winit::dpi::PhysicalSize;
+            let _ = window.request_inner_size(PhysicalSize::new(450, 400));
+
+            use winit::platform::web::WindowExtWebSys;
+            web_sys::window()
+                .and_then(|win| win.document())
+                .and_then(|doc| {
+                    let dst = doc.get_element_by_id("wasm-example")?;
+                    let canvas = web_sys::Element::from(window.canvas()?);
+                    dst.append_child(&canvas).ok()?;
+                    Some(())
+                })
+                .expect("Couldn't append canvas to document body.");
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.state = Some(pollster::block_on(State::<ActiveDemo>::new(window)));
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let proxy = self.proxy.take().expect("resumed should only be called once on wasm32");
+            wasm_bindgen_futures::spawn_local(async move {
+                let state = State::<ActiveDemo>::new(window).await;
+                proxy
+                    .send_event(UserEvent::StateReady(state))
+                    .unwrap_or_else(|_| panic!("event loop dropped before state was ready"));
+            });
+        }
     }
 
-    let mut state = State::new(&window).await;
-    let mut surface_configured = false;
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, event: UserEvent) {
+        let UserEvent::StateReady(state) = event;
+        self.state = Some(state);
+    }
 
-    event_loop.run(move |event, control_flow| match event {
-        Event::Resumed => {
-            log::debug!("Resumed");
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId, event: WindowEvent) {
+        let Some(state) = &mut self.state else { return };
+        if window_id != state.window().id() {
+            return;
         }
-        Event::WindowEvent {
-            ref event,
-            window_id,
-        } if window_id == state.window().id() => if !state.input(event) {
-            match event {
-                WindowEvent::Resized(physical_size) => {
-                    surface_configured = true;
-                    state.resize(*physical_size)
-                },
-                WindowEvent::RedrawRequested => {
-                    state.window().request_redraw();
-
-                    if !surface_configured {
-                        return;
-                    }
-                    
-                    state.update();
-                    match state.render() {
-                        Ok(_) => {},
-                        Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
-                            state.resize(state.size)
-                        },
-                        Err(wgpu::SurfaceError::OutOfMemory) => {
-                            log::error!("Out of memory");
-                            control_flow.exit();
-                        },
-                        Err(wgpu::SurfaceError::Timeout) => {
-                            log::warn!("Surface timeout");
-                        }
+        if state.input(&event) {
+            return;
+        }
+
+        match event {
+            WindowEvent::Resized(physical_size) => {
+                self.surface_configured = true;
+                state.resize(physical_size)
+            },
+            WindowEvent::RedrawRequested => {
+                state.window().request_redraw();
+
+                if !self.surface_configured {
+                    return;
+                }
+
+                state.update();
+                match state.render() {
+                    Ok(_) => {},
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        state.resize(state.size)
+                    },
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        log::error!("Out of memory");
+                        event_loop.exit();
+                    },
+                    Err(wgpu::SurfaceError::Timeout) => {
+                        log::warn!("Surface timeout");
                     }
-                },
-                WindowEvent::CloseRequested
-                | WindowEvent::KeyboardInput {
-                    event:
-                        KeyEvent {
-                            state: ElementState::Pressed,
-                            physical_key: PhysicalKey::Code(KeyCode::Escape),
-                            ..
-                        },
-                    ..
-                } => control_flow.exit(),
-                _ => {}
-            }
+                }
+            },
+            WindowEvent::CloseRequested
+            | WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        state: ElementState::Pressed,
+                        physical_key: PhysicalKey::Code(KeyCode::Escape),
+                        ..
+                    },
+                ..
+            } => event_loop.exit(),
+            _ => {}
         }
-        _ => {}
-    })
-    .unwrap();
+    }
+}
+
+#[cfg_attr(target_arch="wasm32", wasm_bindgen(start))]
+pub fn run() {
+    let event_loop = EventLoop::<UserEvent>::with_user_event().build().unwrap();
+    let proxy = event_loop.create_proxy();
+
+    let mut app = App {
+        proxy: Some(proxy),
+        ..Default::default()
+    };
+
+    event_loop.run_app(&mut app).unwrap();
 }
 
 pub async fn print_adapters() {